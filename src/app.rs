@@ -1,6 +1,6 @@
 use crate::stock::Stock;
 use anyhow::Context;
-use chrono::{DateTime, Duration, Utc};
+use chrono::{Date, DateTime, Datelike, Duration, NaiveDate, TimeZone, Utc, Weekday};
 use im::{ordmap, ordmap::OrdMap};
 use math::round;
 use parking_lot::{RwLock, RwLockWriteGuard};
@@ -18,50 +18,160 @@ use tui::{
 use yahoo_finance::Interval;
 
 pub struct App {
-    pub stock: Stock,
     pub ui_state: UiState,
+    pub watchlist: Watchlist,
 }
 
 impl App {
+    /// Loads `symbol` into the watchlist, adding it as a new entry if it isn't already tracked,
+    /// and makes it the active selection.
     pub async fn load_stock(&mut self, symbol: &str) -> anyhow::Result<()> {
-        self.stock.symbol = symbol.to_ascii_uppercase();
+        let symbol = symbol.to_ascii_uppercase();
+
+        if self.watchlist.find(&symbol).is_none() {
+            self.watchlist.add(symbol.clone())?;
+        } else {
+            self.watchlist.menu_state.select(symbol.clone())?;
+        }
+
+        let entry = self
+            .watchlist
+            .active_entry_mut()
+            .with_context(|| "no active watchlist entry")?;
 
-        self.ui_state.clear_date_range()?;
+        entry.clear_date_range()?;
 
-        self.stock.load_profile().await?;
-        self.stock
-            .load_historical_prices(
-                self.ui_state.time_frame,
-                self.ui_state.start_date,
-                self.ui_state.end_date,
-            )
+        entry.stock.load_profile().await?;
+        entry
+            .stock
+            .load_historical_prices(entry.time_frame, entry.start_date, entry.end_date)
             .await?;
 
         Ok(())
     }
+
+    /// Parses the pending date-range input and applies it to the active watchlist entry.
+    pub fn apply_date_range_input(&mut self) -> anyhow::Result<()> {
+        let (start_date, end_date) = self.ui_state.submit_date_range_input()?;
+
+        let entry = self
+            .watchlist
+            .active_entry_mut()
+            .with_context(|| "no active watchlist entry")?;
+
+        entry.start_date = Some(start_date);
+        entry.end_date = Some(end_date);
+
+        Ok(())
+    }
 }
 
+/// An ordered collection of tracked symbols with their loaded `Stock` data, turning the app from
+/// a single-ticker viewer into a comparison dashboard. Keyboard/mouse selection is delegated to
+/// `menu_state`, the same `MenuState` machinery the time-frame menu uses.
 #[derive(Debug)]
-pub struct UiState {
-    debug_draw: bool,
+pub struct Watchlist {
+    pub entries: Vec<WatchlistEntry>,
+    pub menu_state: MenuState<String>,
+}
+
+impl Watchlist {
+    pub fn find(&self, symbol: &str) -> Option<&WatchlistEntry> {
+        self.entries.iter().find(|entry| entry.symbol == symbol)
+    }
+
+    pub fn active_entry(&self) -> Option<&WatchlistEntry> {
+        let symbol = self.menu_state.selected()?;
+
+        self.find(&symbol)
+    }
+
+    pub fn active_entry_mut(&mut self) -> Option<&mut WatchlistEntry> {
+        let symbol = self.menu_state.selected()?;
+
+        self.entries.iter_mut().find(|entry| entry.symbol == symbol)
+    }
+
+    /// Adds `symbol` to the watchlist unless it is already tracked, and selects it either way.
+    pub fn add(&mut self, symbol: String) -> anyhow::Result<()> {
+        if self.find(&symbol).is_some() {
+            return self.menu_state.select(symbol);
+        }
+
+        self.menu_state.items.push(symbol.clone());
+        self.entries.push(WatchlistEntry::new(symbol.clone()));
+        self.menu_state.select(symbol)?;
+
+        Ok(())
+    }
+
+    /// Removes the currently selected symbol, if any, and selects its former neighbour.
+    pub fn remove_active(&mut self) -> anyhow::Result<()> {
+        let symbol = match self.menu_state.selected() {
+            Some(symbol) => symbol,
+            None => return Ok(()),
+        };
+
+        let index = self
+            .entries
+            .iter()
+            .position(|entry| entry.symbol == symbol)
+            .with_context(|| "selected symbol not found in watchlist")?;
+
+        self.entries.remove(index);
+        self.menu_state.items.remove(index);
+
+        if self.entries.is_empty() {
+            self.menu_state.clear_selection()?;
+        } else {
+            self.menu_state
+                .select_nth(index.min(self.entries.len() - 1))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Default for Watchlist {
+    fn default() -> Self {
+        Self {
+            entries: Vec::new(),
+            menu_state: MenuState::new(Vec::<String>::new()),
+        }
+    }
+}
+
+/// A single tracked symbol: its loaded `Stock` data plus its own time-frame/date-range
+/// selection, so paging or changing the time frame for one symbol doesn't affect the others.
+#[derive(Debug)]
+pub struct WatchlistEntry {
     pub end_date: Option<DateTime<Utc>>,
-    pub frame_rate_counter: FrameRateCounter,
     pub start_date: Option<DateTime<Utc>>,
-    pub stock_symbol_input_state: InputState,
-    target_areas: RwLock<OrdMap<UiTarget, Rect>>,
+    pub stock: Stock,
+    pub symbol: String,
     pub time_frame: TimeFrame,
     pub time_frame_menu_state: MenuState<TimeFrame>,
 }
 
-impl UiState {
-    pub fn debug_draw(&self) -> bool {
-        self.debug_draw
-    }
+impl WatchlistEntry {
+    pub fn new(symbol: String) -> Self {
+        const DEFAULT_TIME_FRAME: TimeFrame = TimeFrame::OneMonth;
 
-    pub fn set_debug_draw(&mut self, debug_draw: bool) -> anyhow::Result<()> {
-        self.debug_draw = debug_draw;
+        let mut stock = Stock::default();
+        stock.symbol = symbol.clone();
 
-        Ok(())
+        Self {
+            end_date: None,
+            start_date: None,
+            stock,
+            symbol,
+            time_frame: DEFAULT_TIME_FRAME,
+            time_frame_menu_state: {
+                let menu_state = MenuState::new(TimeFrame::presets());
+                menu_state.select(DEFAULT_TIME_FRAME).unwrap();
+                menu_state
+            },
+        }
     }
 
     pub fn shift_date_range_before(&mut self, dt: DateTime<Utc>) -> anyhow::Result<()> {
@@ -70,8 +180,10 @@ impl UiState {
             .duration()
             .expect("time frame has no duration");
 
-        let end_date = (dt - Duration::days(1)).date().and_hms(23, 59, 59);
-        let start_date = (end_date - time_frame_duration + Duration::days(1))
+        let sessions = sessions_for_duration(time_frame_duration);
+
+        let end_date = nth_trading_session(dt, 1, -1).date().and_hms(23, 59, 59);
+        let start_date = nth_trading_session(dt, sessions, -1)
             .date()
             .and_hms(0, 0, 0);
 
@@ -87,8 +199,10 @@ impl UiState {
             .duration()
             .expect("time frame has no duration");
 
-        let start_date = (dt + Duration::days(1)).date().and_hms(0, 0, 0);
-        let end_date = (start_date + time_frame_duration - Duration::days(1))
+        let sessions = sessions_for_duration(time_frame_duration);
+
+        let start_date = nth_trading_session(dt, 1, 1).date().and_hms(0, 0, 0);
+        let end_date = nth_trading_session(dt, sessions, 1)
             .date()
             .and_hms(23, 59, 59);
 
@@ -109,6 +223,47 @@ impl UiState {
         Ok(())
     }
 
+    pub fn set_time_frame(&mut self, time_frame: TimeFrame) -> anyhow::Result<()> {
+        self.time_frame = time_frame;
+        self.time_frame_menu_state.select(time_frame)?;
+
+        self.clear_date_range()?;
+
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+pub struct UiState {
+    debug_draw: bool,
+    pub date_range_input_state: InputState,
+    pub frame_rate_counter: FrameRateCounter,
+    pub stock_symbol_input_state: InputState,
+    target_areas: RwLock<OrdMap<UiTarget, Rect>>,
+}
+
+impl UiState {
+    pub fn debug_draw(&self) -> bool {
+        self.debug_draw
+    }
+
+    pub fn set_debug_draw(&mut self, debug_draw: bool) -> anyhow::Result<()> {
+        self.debug_draw = debug_draw;
+
+        Ok(())
+    }
+
+    /// Parses `date_range_input_state.value` as a fuzzy date range (e.g. `"2021-03-01 to
+    /// 2021-06-15"`, `"Jan 2020 - Mar 2020"`, or `"last 30 days"`), clearing the input on success.
+    pub fn submit_date_range_input(&mut self) -> anyhow::Result<(DateTime<Utc>, DateTime<Utc>)> {
+        let date_range = parse_date_range_spec(&self.date_range_input_state.value, Utc::now())
+            .with_context(|| "invalid date range input")?;
+
+        self.date_range_input_state.value.clear();
+
+        Ok(date_range)
+    }
+
     pub fn input_cursor(
         &self,
         input_state: &InputState,
@@ -184,38 +339,173 @@ impl UiState {
 
         Ok(())
     }
-
-    pub fn set_time_frame(&mut self, time_frame: TimeFrame) -> anyhow::Result<()> {
-        self.time_frame = time_frame;
-        self.time_frame_menu_state.select(time_frame)?;
-
-        self.clear_date_range()?;
-
-        Ok(())
-    }
 }
 
 impl Default for UiState {
     fn default() -> Self {
-        const DEFAULT_TIME_FRAME: TimeFrame = TimeFrame::OneMonth;
-
         Self {
             debug_draw: false,
-            end_date: None,
+            date_range_input_state: InputState::default(),
             frame_rate_counter: FrameRateCounter::new(Duration::milliseconds(1_000)),
-            start_date: None,
             stock_symbol_input_state: InputState::default(),
             target_areas: RwLock::new(ordmap! {}),
-            time_frame: DEFAULT_TIME_FRAME,
-            time_frame_menu_state: {
-                let menu_state = MenuState::new(TimeFrame::iter());
-                menu_state.select(DEFAULT_TIME_FRAME).unwrap();
-                menu_state
-            },
         }
     }
 }
 
+/// Shifts `date` off a weekend onto the nearest weekday: Saturday observances move to Friday,
+/// Sunday observances move to Monday.
+fn observed(date: Date<Utc>) -> Date<Utc> {
+    match date.weekday() {
+        Weekday::Sat => date - Duration::days(1),
+        Weekday::Sun => date + Duration::days(1),
+        _ => date,
+    }
+}
+
+/// The date of the `n`-th `weekday` in `year`-`month` (`n` is 1-based).
+fn nth_weekday_of_month(year: i32, month: u32, weekday: Weekday, n: i64) -> Date<Utc> {
+    let first = Utc.ymd(year, month, 1);
+    let first_weekday = first.weekday().num_days_from_monday() as i64;
+    let target_weekday = weekday.num_days_from_monday() as i64;
+    let offset = (target_weekday - first_weekday).rem_euclid(7);
+
+    first + Duration::days(offset + 7 * (n - 1))
+}
+
+/// The date of the last `weekday` in `year`-`month`.
+fn last_weekday_of_month(year: i32, month: u32, weekday: Weekday) -> Date<Utc> {
+    let (next_year, next_month) = if month == 12 {
+        (year + 1, 1)
+    } else {
+        (year, month + 1)
+    };
+
+    let mut date = Utc.ymd(next_year, next_month, 1) - Duration::days(1);
+    while date.weekday() != weekday {
+        date = date - Duration::days(1);
+    }
+
+    date
+}
+
+/// Easter Sunday for `year`, via the anonymous Gregorian algorithm.
+fn easter_sunday(year: i32) -> Date<Utc> {
+    let a = year % 19;
+    let b = year / 100;
+    let c = year % 100;
+    let d = b / 4;
+    let e = b % 4;
+    let f = (b + 8) / 25;
+    let g = (b - f + 1) / 3;
+    let h = (19 * a + b - d - g + 15) % 30;
+    let i = c / 4;
+    let k = c % 4;
+    let l = (32 + 2 * e + 2 * i - h - k) % 7;
+    let m = (a + 11 * h + 22 * l) / 451;
+    let month = (h + l - 7 * m + 114) / 31;
+    let day = (h + l - 7 * m + 114) % 31 + 1;
+
+    Utc.ymd(year, month as u32, day as u32)
+}
+
+/// The US market holidays observed in `year`, computed rather than looked up from a fixed table
+/// so the holiday set doesn't go stale in years after it was written: New Year's Day, MLK Day,
+/// Washington's Birthday, Good Friday, Memorial Day, Juneteenth, Independence Day, Labor Day,
+/// Thanksgiving, and Christmas.
+fn us_market_holidays(year: i32) -> Vec<Date<Utc>> {
+    vec![
+        observed(Utc.ymd(year, 1, 1)),
+        nth_weekday_of_month(year, 1, Weekday::Mon, 3),
+        nth_weekday_of_month(year, 2, Weekday::Mon, 3),
+        easter_sunday(year) - Duration::days(2),
+        last_weekday_of_month(year, 5, Weekday::Mon),
+        observed(Utc.ymd(year, 6, 19)),
+        observed(Utc.ymd(year, 7, 4)),
+        nth_weekday_of_month(year, 9, Weekday::Mon, 1),
+        nth_weekday_of_month(year, 11, Weekday::Thu, 4),
+        observed(Utc.ymd(year, 12, 25)),
+    ]
+}
+
+/// The default trading-day holiday filter: a year-agnostic US market holiday calendar, derived
+/// from `us_market_holidays` for whatever year `date` falls in.
+fn is_us_market_holiday(date: DateTime<Utc>) -> bool {
+    us_market_holidays(date.year()).contains(&date.date())
+}
+
+/// Whether `date` is a trading session: a weekday not flagged by `is_holiday`.
+fn is_trading_day(date: DateTime<Utc>, is_holiday: &impl Fn(DateTime<Utc>) -> bool) -> bool {
+    if matches!(date.weekday(), Weekday::Sat | Weekday::Sun) {
+        return false;
+    }
+
+    !is_holiday(date)
+}
+
+/// Walks calendar days one at a time from `start`, stepping by `direction` (`1` to walk forward,
+/// `-1` to walk backward), and yields only the dates accepted by `is_holiday`'s complement.
+/// Modeled after an RRULE recurrence: the `counter_date` advances a day at a time and only
+/// trading days are handed back to the caller. The holiday set is injectable so callers aren't
+/// stuck with `is_us_market_holiday`.
+struct TradingSessionIter<F>
+where
+    F: Fn(DateTime<Utc>) -> bool,
+{
+    counter_date: DateTime<Utc>,
+    direction: i64,
+    is_holiday: F,
+}
+
+impl<F> Iterator for TradingSessionIter<F>
+where
+    F: Fn(DateTime<Utc>) -> bool,
+{
+    type Item = DateTime<Utc>;
+
+    fn next(&mut self) -> Option<DateTime<Utc>> {
+        loop {
+            self.counter_date = self.counter_date + Duration::days(self.direction);
+
+            if is_trading_day(self.counter_date, &self.is_holiday) {
+                return Some(self.counter_date);
+            }
+        }
+    }
+}
+
+/// The `sessions`-th trading day before (`direction = -1`) or after (`direction = 1`) `start`,
+/// using a caller-supplied holiday filter.
+fn nth_trading_session_with_holidays<F>(
+    start: DateTime<Utc>,
+    sessions: i64,
+    direction: i64,
+    is_holiday: F,
+) -> DateTime<Utc>
+where
+    F: Fn(DateTime<Utc>) -> bool,
+{
+    TradingSessionIter {
+        counter_date: start,
+        direction,
+        is_holiday,
+    }
+    .nth((sessions - 1).max(0) as usize)
+    .expect("trading session iterator never terminates")
+}
+
+/// The `sessions`-th trading day before (`direction = -1`) or after (`direction = 1`) `start`,
+/// using the default `is_us_market_holiday` calendar.
+fn nth_trading_session(start: DateTime<Utc>, sessions: i64, direction: i64) -> DateTime<Utc> {
+    nth_trading_session_with_holidays(start, sessions, direction, is_us_market_holiday)
+}
+
+/// Approximates how many trading sessions fit in a calendar-day span, at ~5 sessions per 7-day
+/// week, so "page back one month" moves by ~21 sessions instead of 30 raw calendar days.
+fn sessions_for_duration(duration: Duration) -> i64 {
+    ((duration.num_days() as f64 * 5.0 / 7.0).round() as i64).max(1)
+}
+
 #[derive(Debug)]
 pub struct MenuState<T>
 where
@@ -322,21 +612,25 @@ impl Default for InputState {
 
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 pub enum UiTarget {
+    DateRangeInput,
     StockName,
     StockSymbol,
     StockSymbolInput,
     TimeFrame,
     TimeFrameMenu,
+    Watchlist,
 }
 
 impl UiTarget {
     pub fn zindex(self) -> i8 {
         match self {
+            Self::DateRangeInput => 1,
             Self::StockName => 0,
             Self::StockSymbol => 0,
             Self::StockSymbolInput => 1,
             Self::TimeFrame => 0,
             Self::TimeFrameMenu => 1,
+            Self::Watchlist => 0,
         }
     }
 }
@@ -420,9 +714,17 @@ pub enum TimeFrame {
     FiveYears,
     TenYears,
     Max,
+    /// A user-supplied lookback window that doesn't correspond to one of the canned presets,
+    /// e.g. `"45d"` or `"2y6mo"`.
+    Custom(Duration),
 }
 
 impl TimeFrame {
+    /// The non-`Custom` presets offered by the time frame menu.
+    pub fn presets() -> impl Iterator<Item = Self> {
+        Self::iter().filter(|time_frame| !matches!(time_frame, Self::Custom(_)))
+    }
+
     pub fn duration(self) -> Option<Duration> {
         match self {
             Self::FiveDays => Some(Duration::days(5)),
@@ -433,6 +735,7 @@ impl TimeFrame {
             Self::TwoYears => Some(Duration::days(30 * 12 * 2)),
             Self::FiveYears => Some(Duration::days(30 * 12 * 5)),
             Self::TenYears => Some(Duration::days(30 * 12 * 10)),
+            Self::Custom(duration) => Some(duration),
             _ => None,
         }
     }
@@ -449,6 +752,33 @@ impl TimeFrame {
             Self::FiveYears => Interval::_5y,
             Self::TenYears => Interval::_10y,
             Self::Max => Interval::_max,
+            Self::Custom(duration) => Self::closest_coarser_interval(duration),
+        }
+    }
+
+    /// Picks the narrowest canned interval whose span is at least as long as `duration`,
+    /// falling back to `_max` for anything longer than ten years.
+    fn closest_coarser_interval(duration: Duration) -> Interval {
+        let days = duration.num_days();
+
+        if days <= 5 {
+            Interval::_5d
+        } else if days <= 30 {
+            Interval::_1mo
+        } else if days <= 30 * 3 {
+            Interval::_3mo
+        } else if days <= 30 * 6 {
+            Interval::_6mo
+        } else if days <= 30 * 12 {
+            Interval::_1y
+        } else if days <= 30 * 12 * 2 {
+            Interval::_2y
+        } else if days <= 30 * 12 * 5 {
+            Interval::_5y
+        } else if days <= 30 * 12 * 10 {
+            Interval::_10y
+        } else {
+            Interval::_max
         }
     }
 }
@@ -469,9 +799,82 @@ impl FromStr for TimeFrame {
             "10Y" | "10y" => Ok(Self::TenYears),
             "MAX" | "max" => Ok(Self::Max),
             "" => Err(ParseTimeFrameError::Empty),
-            _ => Err(ParseTimeFrameError::Invalid),
+            _ => Ok(Self::Custom(parse_duration_spec(s)?)),
+        }
+    }
+}
+
+/// An arbitrary but sane upper bound on a parsed duration (200 years), so a pathological count
+/// like `"999999999999y"` can't overflow or produce a nonsensical `Duration`.
+const MAX_DURATION_SPEC_DAYS: i64 = 365 * 200;
+
+/// Parses a free-form duration spec such as `"45d"`, `"18mo"`, `"2y6mo"` or `"90 days"` into a
+/// total `Duration`, approximating a month as 30 days and a year as 365 days.
+fn parse_duration_spec(s: &str) -> Result<Duration, ParseTimeFrameError> {
+    let trimmed = s.trim();
+
+    if trimmed.is_empty() {
+        return Err(ParseTimeFrameError::Empty);
+    }
+
+    let mut chars = trimmed.chars().peekable();
+    let mut total = Duration::zero();
+    let mut found_term = false;
+
+    while chars.peek().is_some() {
+        while chars.peek().map_or(false, |c| c.is_whitespace()) {
+            chars.next();
+        }
+
+        if chars.peek().is_none() {
+            break;
+        }
+
+        let mut number = String::new();
+        while chars.peek().map_or(false, |c| c.is_ascii_digit()) {
+            number.push(chars.next().unwrap());
+        }
+
+        if number.is_empty() {
+            return Err(ParseTimeFrameError::Invalid);
+        }
+
+        while chars.peek().map_or(false, |c| c.is_whitespace()) {
+            chars.next();
+        }
+
+        let mut unit = String::new();
+        while chars.peek().map_or(false, |c| c.is_alphabetic()) {
+            unit.push(chars.next().unwrap().to_ascii_lowercase());
+        }
+
+        let count: i64 = number.parse().map_err(|_| ParseTimeFrameError::Invalid)?;
+        let unit_days = match unit.as_str() {
+            "d" | "day" | "days" => 1,
+            "w" | "week" | "weeks" => 7,
+            "mo" | "month" | "months" => 30,
+            "y" | "yr" | "yrs" | "year" | "years" => 365,
+            _ => return Err(ParseTimeFrameError::Invalid),
+        };
+
+        let term_days = count
+            .checked_mul(unit_days)
+            .filter(|days| *days <= MAX_DURATION_SPEC_DAYS)
+            .ok_or(ParseTimeFrameError::Invalid)?;
+
+        total = total + Duration::days(term_days);
+        found_term = true;
+
+        if total > Duration::days(MAX_DURATION_SPEC_DAYS) {
+            return Err(ParseTimeFrameError::Invalid);
         }
     }
+
+    if !found_term || total <= Duration::zero() {
+        return Err(ParseTimeFrameError::Invalid);
+    }
+
+    Ok(total)
 }
 
 #[derive(Debug, Error)]
@@ -495,6 +898,554 @@ impl fmt::Display for TimeFrame {
             Self::FiveYears => write!(f, "5Y"),
             Self::TenYears => write!(f, "10Y"),
             Self::Max => write!(f, "MAX"),
+            Self::Custom(duration) => write!(f, "{}D", duration.num_days()),
         }
     }
 }
+
+const MONTH_NAMES: &[(&str, &str, u32)] = &[
+    ("jan", "january", 1),
+    ("feb", "february", 2),
+    ("mar", "march", 3),
+    ("apr", "april", 4),
+    ("may", "may", 5),
+    ("jun", "june", 6),
+    ("jul", "july", 7),
+    ("aug", "august", 8),
+    ("sep", "september", 9),
+    ("oct", "october", 10),
+    ("nov", "november", 11),
+    ("dec", "december", 12),
+];
+
+fn month_from_name(name: &str) -> Option<u32> {
+    let name = name.to_ascii_lowercase();
+
+    MONTH_NAMES
+        .iter()
+        .find(|(abbr, full, _)| *abbr == name || *full == name)
+        .map(|(_, _, month)| *month)
+}
+
+/// Parses `"<month name> <year>"` (e.g. `"Jan 2020"` or `"January 2020"`).
+fn parse_month_year(s: &str) -> Option<(u32, i32)> {
+    let mut tokens = s.split_whitespace();
+    let month = month_from_name(tokens.next()?)?;
+    let year = tokens.next()?.parse().ok()?;
+
+    if tokens.next().is_some() {
+        return None;
+    }
+
+    Some((month, year))
+}
+
+/// The first moment of `year`-`month` if `is_start`, else the last moment of that month. Returns
+/// `ParseDateRangeError::Invalid` rather than panicking when `year`/`month` fall outside chrono's
+/// representable range.
+fn month_bound(
+    year: i32,
+    month: u32,
+    is_start: bool,
+) -> Result<DateTime<Utc>, ParseDateRangeError> {
+    if is_start {
+        let date = Utc
+            .ymd_opt(year, month, 1)
+            .single()
+            .ok_or(ParseDateRangeError::Invalid)?;
+
+        Ok(date.and_hms(0, 0, 0))
+    } else {
+        let (next_year, next_month) = if month == 12 {
+            (year + 1, 1)
+        } else {
+            (year, month + 1)
+        };
+
+        let next_month_start = Utc
+            .ymd_opt(next_year, next_month, 1)
+            .single()
+            .ok_or(ParseDateRangeError::Invalid)?;
+
+        Ok((next_month_start.and_hms(0, 0, 0) - Duration::days(1))
+            .date()
+            .and_hms(23, 59, 59))
+    }
+}
+
+/// The first moment of `year` if `is_start`, else the last moment of that year. Returns
+/// `ParseDateRangeError::Invalid` rather than panicking when `year` falls outside chrono's
+/// representable range.
+fn year_bound(year: i32, is_start: bool) -> Result<DateTime<Utc>, ParseDateRangeError> {
+    if is_start {
+        let date = Utc
+            .ymd_opt(year, 1, 1)
+            .single()
+            .ok_or(ParseDateRangeError::Invalid)?;
+
+        Ok(date.and_hms(0, 0, 0))
+    } else {
+        let date = Utc
+            .ymd_opt(year, 12, 31)
+            .single()
+            .ok_or(ParseDateRangeError::Invalid)?;
+
+        Ok(date.and_hms(23, 59, 59))
+    }
+}
+
+/// Tolerantly parses one side of a date range: ISO `%Y-%m-%d`, then `%b %Y` / `%B %Y`, then a
+/// bare year, filling in the missing month/day as the first or last of the period depending on
+/// whether it is the start (`is_start`) or end bound.
+fn parse_date_bound(s: &str, is_start: bool) -> Result<DateTime<Utc>, ParseDateRangeError> {
+    let s = s.trim();
+
+    if s.eq_ignore_ascii_case("today") {
+        return Ok(Utc::now());
+    }
+
+    if let Ok(date) = NaiveDate::parse_from_str(s, "%Y-%m-%d") {
+        return Ok(if is_start {
+            Utc.from_utc_date(&date).and_hms(0, 0, 0)
+        } else {
+            Utc.from_utc_date(&date).and_hms(23, 59, 59)
+        });
+    }
+
+    if let Some((month, year)) = parse_month_year(s) {
+        return month_bound(year, month, is_start);
+    }
+
+    if let Ok(year) = s.parse::<i32>() {
+        return year_bound(year, is_start);
+    }
+
+    Err(ParseDateRangeError::Invalid)
+}
+
+fn strip_ci_prefix<'a>(s: &'a str, prefix: &str) -> Option<&'a str> {
+    if s.len() >= prefix.len() && s[..prefix.len()].eq_ignore_ascii_case(prefix) {
+        Some(&s[prefix.len()..])
+    } else {
+        None
+    }
+}
+
+/// Splits on the first of the range separators `" to "`, `" .. "`, `" - "` (checked in that
+/// order), case-insensitively, returning the trimmed bounds on either side.
+fn split_date_range(s: &str) -> Option<(&str, &str)> {
+    let lower = s.to_ascii_lowercase();
+
+    for separator in [" to ", " .. ", " - "].iter() {
+        if let Some(index) = lower.find(separator) {
+            return Some((s[..index].trim(), s[index + separator.len()..].trim()));
+        }
+    }
+
+    None
+}
+
+/// Tolerantly parses an explicit date range spec such as `"2021-03-01 to 2021-06-15"`, `"Jan
+/// 2020 - Mar 2020"`, or `"last 30 days"` relative to `now`. A single bound (no separator) is
+/// treated as the start of the range, anchoring the end to `now`.
+fn parse_date_range_spec(
+    s: &str,
+    now: DateTime<Utc>,
+) -> Result<(DateTime<Utc>, DateTime<Utc>), ParseDateRangeError> {
+    let trimmed = s.trim();
+
+    if trimmed.is_empty() {
+        return Err(ParseDateRangeError::Empty);
+    }
+
+    if let Some(rest) = strip_ci_prefix(trimmed, "last ") {
+        let duration = parse_duration_spec(rest).map_err(|_| ParseDateRangeError::Invalid)?;
+        let end_date = now.date().and_hms(23, 59, 59);
+        let start_date = (end_date - duration + Duration::days(1))
+            .date()
+            .and_hms(0, 0, 0);
+
+        return Ok((start_date, end_date));
+    }
+
+    match split_date_range(trimmed) {
+        Some((start, end)) => {
+            let start_date = parse_date_bound(start, true)?;
+            let end_date = parse_date_bound(end, false)?;
+
+            Ok((start_date, end_date))
+        }
+        None => {
+            let start_date = parse_date_bound(trimmed, true)?;
+            let end_date = now.date().and_hms(23, 59, 59);
+
+            Ok((start_date, end_date))
+        }
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum ParseDateRangeError {
+    #[error("cannot parse date range from empty string")]
+    Empty,
+    #[error("invalid date range literal")]
+    Invalid,
+}
+
+/// A calendar period used to snap timestamps to tick boundaries, ordered finest to coarsest.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DateAxisPeriod {
+    Day,
+    Week,
+    Month,
+    Quarter,
+    Year,
+}
+
+impl DateAxisPeriod {
+    /// All periods, ordered from finest to coarsest.
+    const ALL: [Self; 5] = [
+        Self::Day,
+        Self::Week,
+        Self::Month,
+        Self::Quarter,
+        Self::Year,
+    ];
+
+    /// An approximate duration used only to estimate how many ticks a period would produce over
+    /// a span; actual boundaries are calendar-aligned, not fixed-width.
+    fn approx_duration(self) -> Duration {
+        match self {
+            Self::Year => Duration::days(365),
+            Self::Quarter => Duration::days(91),
+            Self::Month => Duration::days(30),
+            Self::Week => Duration::days(7),
+            Self::Day => Duration::days(1),
+        }
+    }
+
+    /// Snaps `dt` down to the start of the period it falls in.
+    pub fn date_floor(self, dt: DateTime<Utc>) -> DateTime<Utc> {
+        let date = dt.date();
+
+        match self {
+            Self::Year => Utc.ymd(date.year(), 1, 1).and_hms(0, 0, 0),
+            Self::Quarter => {
+                let quarter_month = (date.month0() / 3) * 3 + 1;
+                Utc.ymd(date.year(), quarter_month, 1).and_hms(0, 0, 0)
+            }
+            Self::Month => Utc.ymd(date.year(), date.month(), 1).and_hms(0, 0, 0),
+            Self::Week => {
+                let days_from_monday = date.weekday().num_days_from_monday();
+                (date - Duration::days(days_from_monday as i64)).and_hms(0, 0, 0)
+            }
+            Self::Day => date.and_hms(0, 0, 0),
+        }
+    }
+
+    /// Snaps `dt` up to the start of the next period boundary, or returns `dt` unchanged if it is
+    /// already on one.
+    pub fn date_ceil(self, dt: DateTime<Utc>) -> DateTime<Utc> {
+        let floor = self.date_floor(dt);
+
+        if floor == dt {
+            floor
+        } else {
+            self.next_boundary(floor)
+        }
+    }
+
+    /// The start of the period immediately following the one `floor` (itself a period boundary)
+    /// falls in.
+    fn next_boundary(self, floor: DateTime<Utc>) -> DateTime<Utc> {
+        match self {
+            Self::Year => Utc.ymd(floor.year() + 1, 1, 1).and_hms(0, 0, 0),
+            Self::Quarter => {
+                let (year, month) = if floor.month() >= 10 {
+                    (floor.year() + 1, 1)
+                } else {
+                    (floor.year(), floor.month() + 3)
+                };
+                Utc.ymd(year, month, 1).and_hms(0, 0, 0)
+            }
+            Self::Month => {
+                let (year, month) = if floor.month() == 12 {
+                    (floor.year() + 1, 1)
+                } else {
+                    (floor.year(), floor.month() + 1)
+                };
+                Utc.ymd(year, month, 1).and_hms(0, 0, 0)
+            }
+            Self::Week => floor + Duration::days(7),
+            Self::Day => floor + Duration::days(1),
+        }
+    }
+}
+
+/// Maps a `DateTime<Utc>` range onto pixel/cell columns and generates calendar-aligned tick
+/// key-points, mirroring plotters' `TimeValue` axis abstraction so the price chart can render
+/// evenly spaced, human-friendly date labels instead of arbitrary slices.
+#[derive(Clone, Copy, Debug)]
+pub struct DateAxis {
+    pub begin: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+}
+
+impl DateAxis {
+    pub fn new(begin: DateTime<Utc>, end: DateTime<Utc>) -> Self {
+        Self { begin, end }
+    }
+
+    /// Linearly interpolates `value`'s position between `begin` and `end` onto the `(left,
+    /// right)` coordinate range.
+    pub fn map_coord(&self, value: DateTime<Utc>, (left, right): (i32, i32)) -> i32 {
+        let span = (self.end - self.begin).num_milliseconds().max(1) as f64;
+        let offset = (value - self.begin).num_milliseconds() as f64;
+        let ratio = (offset / span).max(0.0).min(1.0);
+
+        left + ((right - left) as f64 * ratio).round() as i32
+    }
+
+    /// Picks the finest period whose estimated tick count over `[begin, end]` (the span divided by
+    /// the period's approximate duration, rounded up) fits within `max_points`, or `Year` if none
+    /// does, then returns that period's calendar-aligned boundaries within the range. The estimate
+    /// is approximate, so the result can hold more than `max_points` boundaries.
+    pub fn key_points(&self, max_points: usize) -> Vec<DateTime<Utc>> {
+        let max_points = max_points.max(1);
+        let span = self.end - self.begin;
+
+        let period = DateAxisPeriod::ALL
+            .iter()
+            .copied()
+            .find(|period| {
+                let period_ms = period.approx_duration().num_milliseconds() as f64;
+                let count = span.num_milliseconds() as f64 / period_ms;
+
+                count.ceil() as usize <= max_points
+            })
+            .unwrap_or(DateAxisPeriod::Year);
+
+        let mut points = Vec::new();
+        let mut current = period.date_ceil(self.begin);
+
+        while current <= self.end {
+            points.push(current);
+            current = period.next_boundary(current);
+        }
+
+        points
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_duration_spec_accepts_single_terms() {
+        assert_eq!(parse_duration_spec("45d").unwrap(), Duration::days(45));
+        assert_eq!(
+            parse_duration_spec("18mo").unwrap(),
+            Duration::days(18 * 30)
+        );
+        assert_eq!(parse_duration_spec("90 days").unwrap(), Duration::days(90));
+        assert_eq!(parse_duration_spec("2 weeks").unwrap(), Duration::days(14));
+        assert_eq!(parse_duration_spec("1y").unwrap(), Duration::days(365));
+    }
+
+    #[test]
+    fn parse_duration_spec_accumulates_multiple_terms() {
+        assert_eq!(
+            parse_duration_spec("2y6mo").unwrap(),
+            Duration::days(2 * 365 + 6 * 30)
+        );
+    }
+
+    #[test]
+    fn parse_duration_spec_rejects_empty_and_trailing_garbage() {
+        assert!(matches!(
+            parse_duration_spec(""),
+            Err(ParseTimeFrameError::Empty)
+        ));
+        assert!(matches!(
+            parse_duration_spec("45dd"),
+            Err(ParseTimeFrameError::Invalid)
+        ));
+        assert!(matches!(
+            parse_duration_spec("45x"),
+            Err(ParseTimeFrameError::Invalid)
+        ));
+        assert!(matches!(
+            parse_duration_spec("abc"),
+            Err(ParseTimeFrameError::Invalid)
+        ));
+    }
+
+    #[test]
+    fn parse_duration_spec_rejects_non_positive_totals() {
+        assert!(matches!(
+            parse_duration_spec("0d"),
+            Err(ParseTimeFrameError::Invalid)
+        ));
+        assert!(matches!(
+            parse_duration_spec("0d0mo"),
+            Err(ParseTimeFrameError::Invalid)
+        ));
+    }
+
+    #[test]
+    fn parse_duration_spec_rejects_pathological_counts() {
+        assert!(matches!(
+            parse_duration_spec("999999999999999y"),
+            Err(ParseTimeFrameError::Invalid)
+        ));
+        assert!(matches!(
+            parse_duration_spec("100000y"),
+            Err(ParseTimeFrameError::Invalid)
+        ));
+    }
+
+    #[test]
+    fn time_frame_from_str_falls_back_to_custom() {
+        assert_eq!(
+            TimeFrame::from_str("45d").unwrap(),
+            TimeFrame::Custom(Duration::days(45))
+        );
+        assert!(TimeFrame::from_str("").is_err());
+    }
+
+    #[test]
+    fn parse_date_range_spec_handles_iso_range() {
+        let now = Utc.ymd(2026, 7, 26).and_hms(12, 0, 0);
+        let (start, end) = parse_date_range_spec("2021-03-01 to 2021-06-15", now).unwrap();
+
+        assert_eq!(start, Utc.ymd(2021, 3, 1).and_hms(0, 0, 0));
+        assert_eq!(end, Utc.ymd(2021, 6, 15).and_hms(23, 59, 59));
+    }
+
+    #[test]
+    fn parse_date_range_spec_handles_month_year_range() {
+        let now = Utc.ymd(2026, 7, 26).and_hms(12, 0, 0);
+        let (start, end) = parse_date_range_spec("Jan 2020 - Mar 2020", now).unwrap();
+
+        assert_eq!(start, Utc.ymd(2020, 1, 1).and_hms(0, 0, 0));
+        assert_eq!(end, Utc.ymd(2020, 3, 31).and_hms(23, 59, 59));
+    }
+
+    #[test]
+    fn parse_date_range_spec_handles_last_n_days() {
+        let now = Utc.ymd(2026, 7, 26).and_hms(12, 0, 0);
+        let (start, end) = parse_date_range_spec("last 30 days", now).unwrap();
+
+        assert_eq!(end, now.date().and_hms(23, 59, 59));
+        assert_eq!(start, (end - Duration::days(29)).date().and_hms(0, 0, 0));
+    }
+
+    #[test]
+    fn parse_date_range_spec_anchors_single_bound_to_now() {
+        let now = Utc.ymd(2026, 7, 26).and_hms(12, 0, 0);
+        let (start, end) = parse_date_range_spec("2021-03-01", now).unwrap();
+
+        assert_eq!(start, Utc.ymd(2021, 3, 1).and_hms(0, 0, 0));
+        assert_eq!(end, now.date().and_hms(23, 59, 59));
+    }
+
+    #[test]
+    fn parse_date_range_spec_rejects_empty() {
+        assert!(matches!(
+            parse_date_range_spec("", Utc::now()),
+            Err(ParseDateRangeError::Empty)
+        ));
+    }
+
+    #[test]
+    fn parse_date_range_spec_rejects_out_of_range_years_without_panicking() {
+        let now = Utc::now();
+
+        assert!(matches!(
+            parse_date_range_spec("300000", now),
+            Err(ParseDateRangeError::Invalid)
+        ));
+        assert!(matches!(
+            parse_date_range_spec("Jan 262144", now),
+            Err(ParseDateRangeError::Invalid)
+        ));
+        assert!(matches!(
+            parse_date_range_spec("2020 to 999999", now),
+            Err(ParseDateRangeError::Invalid)
+        ));
+    }
+
+    #[test]
+    fn nth_trading_session_skips_weekends() {
+        // 2026-07-24 is a Friday; the next trading day is Monday 2026-07-27.
+        let friday = Utc.ymd(2026, 7, 24).and_hms(0, 0, 0);
+        let next = nth_trading_session(friday, 1, 1);
+
+        assert_eq!(next.date(), Utc.ymd(2026, 7, 27));
+    }
+
+    #[test]
+    fn nth_trading_session_skips_computed_holidays() {
+        // Independence Day 2026 (Sat 7/4) is observed Friday 7/3; the trading day before it is
+        // Thursday 7/2, not Friday.
+        let after = Utc.ymd(2026, 7, 6).and_hms(0, 0, 0);
+        let prev = nth_trading_session(after, 1, -1);
+
+        assert_eq!(prev.date(), Utc.ymd(2026, 7, 2));
+    }
+
+    #[test]
+    fn sessions_for_duration_approximates_five_of_seven() {
+        assert_eq!(sessions_for_duration(Duration::days(30)), 21);
+        assert_eq!(sessions_for_duration(Duration::days(7)), 5);
+    }
+
+    #[test]
+    fn date_axis_period_floor_and_ceil_snap_to_boundaries() {
+        let mid_month = Utc.ymd(2026, 3, 15).and_hms(8, 0, 0);
+
+        assert_eq!(
+            DateAxisPeriod::Month.date_floor(mid_month),
+            Utc.ymd(2026, 3, 1).and_hms(0, 0, 0)
+        );
+        assert_eq!(
+            DateAxisPeriod::Month.date_ceil(mid_month),
+            Utc.ymd(2026, 4, 1).and_hms(0, 0, 0)
+        );
+
+        let on_boundary = Utc.ymd(2026, 1, 1).and_hms(0, 0, 0);
+        assert_eq!(DateAxisPeriod::Year.date_ceil(on_boundary), on_boundary);
+    }
+
+    #[test]
+    fn date_axis_key_points_pick_the_finest_period_that_fits() {
+        let axis = DateAxis::new(
+            Utc.ymd(2020, 1, 1).and_hms(0, 0, 0),
+            Utc.ymd(2026, 1, 1).and_hms(0, 0, 0),
+        );
+
+        let points = axis.key_points(10);
+
+        assert!(points.len() <= 10);
+        assert!(points.windows(2).all(|pair| pair[0] < pair[1]));
+        // Quarters would need ~25 ticks over six years, so years are the finest period that fits.
+        assert_eq!(
+            points,
+            (2020..=2026)
+                .map(|year| Utc.ymd(year, 1, 1).and_hms(0, 0, 0))
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn date_axis_map_coord_interpolates_linearly() {
+        let axis = DateAxis::new(
+            Utc.ymd(2026, 1, 1).and_hms(0, 0, 0),
+            Utc.ymd(2026, 1, 11).and_hms(0, 0, 0),
+        );
+        let midpoint = Utc.ymd(2026, 1, 6).and_hms(0, 0, 0);
+
+        assert_eq!(axis.map_coord(midpoint, (0, 100)), 50);
+    }
+}